@@ -0,0 +1,74 @@
+//! Access records for DPOR dependency tracking.
+
+use crate::clock::VectorClock;
+
+/// The kind of access performed on a shared object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessKind {
+    Read,
+    Write,
+    /// A Stacked-Borrows retag. Behaves like a `Write` for dependency
+    /// purposes: a concurrent retag can invalidate a reference before
+    /// it is used, so DPOR must create the same backtrack points it
+    /// would for a write.
+    Retag,
+}
+
+/// The memory ordering of an atomic access. Mirrors `std::sync::atomic::Ordering`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemOrdering {
+    Relaxed,
+    Acquire,
+    Release,
+    AcqRel,
+    SeqCst,
+}
+
+/// A single access to a shared object, tagged with enough metadata for
+/// `ObjectState` to compute dependencies and render backtrack points.
+#[derive(Clone, Debug)]
+pub struct Access {
+    /// The thread that performed the access.
+    pub thread_id: usize,
+    /// Identifies the scheduling path at which this access occurred, so
+    /// two accesses from the same thread at different exploration points
+    /// are not conflated when deduping backtrack targets.
+    pub path_id: u64,
+    /// The accessing thread's vector clock at the time of the access,
+    /// used to determine whether two accesses are genuinely concurrent
+    /// or already ordered by a happens-before edge.
+    pub clock: VectorClock,
+    /// `Some(ordering)` if this is an atomic access with the given
+    /// memory ordering; `None` for a plain (non-atomic) access. A
+    /// conflicting pair where either side is `None` is a hard data-race
+    /// error; an all-atomic conflicting pair is merely a DPOR dependency
+    /// to reorder.
+    pub ordering: Option<MemOrdering>,
+}
+
+impl Access {
+    /// Creates a plain, non-atomic access.
+    pub fn new(thread_id: usize, path_id: u64, clock: VectorClock) -> Self {
+        Self {
+            thread_id,
+            path_id,
+            clock,
+            ordering: None,
+        }
+    }
+
+    /// Creates an atomic access with the given memory ordering.
+    pub fn atomic(thread_id: usize, path_id: u64, clock: VectorClock, ordering: MemOrdering) -> Self {
+        Self {
+            thread_id,
+            path_id,
+            clock,
+            ordering: Some(ordering),
+        }
+    }
+
+    /// Returns true if this access is atomic.
+    pub fn is_atomic(&self) -> bool {
+        self.ordering.is_some()
+    }
+}
@@ -0,0 +1,134 @@
+//! Stacked Borrows aliasing model layered on top of `ObjectState`.
+//!
+//! Each shared object carries a stack of borrow items recording which
+//! tags may presently access it and how. Retagging derives a fresh tag
+//! from a parent and pushes a new item; concrete reads and writes pop
+//! the stack down to (or reject) the item matching the accessing tag.
+//! This catches aliasing violations under Rust's aliasing model that a
+//! plain read/write race detector would miss, because DPOR can then
+//! discover interleavings where one thread invalidates another's
+//! reference mid-exploration.
+
+use std::fmt;
+
+/// Opaque tag identifying a borrow. Allocated monotonically by
+/// [`BorrowStack::retag`] and never reused.
+pub type BorrowTag = u64;
+
+/// The permission granted to a borrow item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+    /// Exclusive access: a read or write through any tag below it on the
+    /// stack pops it.
+    Unique,
+    /// Shared, mutable access: grants reads and writes through its own
+    /// tag and, like `Unique`, is popped by a read or write through any
+    /// tag below it, including a sibling derived from the same parent.
+    SharedReadWrite,
+    /// Shared, read-only access: frozen. A read through another tag
+    /// leaves it intact; only a write pops it.
+    SharedReadOnly,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BorrowItem {
+    tag: BorrowTag,
+    perm: Permission,
+}
+
+/// An access through a tag the object's borrow stack does not permit:
+/// the tag is no longer on the stack, or a write goes through a tag whose
+/// only item is `SharedReadOnly`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AliasingError {
+    /// The tag whose access was rejected.
+    pub tag: BorrowTag,
+}
+
+impl fmt::Display for AliasingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tag {} does not permit this access (popped from the borrow stack, or read-only)",
+            self.tag
+        )
+    }
+}
+
+impl std::error::Error for AliasingError {}
+
+/// Per-object borrow stack implementing the Stacked Borrows discipline.
+#[derive(Clone, Debug)]
+pub struct BorrowStack {
+    items: Vec<BorrowItem>,
+    next_tag: BorrowTag,
+}
+
+impl BorrowStack {
+    /// Creates a fresh stack rooted at a single `Unique` borrow (tag `0`)
+    /// representing the object's owning reference.
+    pub fn new() -> Self {
+        Self {
+            items: vec![BorrowItem {
+                tag: 0,
+                perm: Permission::Unique,
+            }],
+            next_tag: 1,
+        }
+    }
+
+    /// Derives a fresh tag from `parent` and pushes a new item with the
+    /// given permission. Returns the new tag, or an `AliasingError` if
+    /// `parent` is no longer on the stack.
+    pub fn retag(&mut self, parent: BorrowTag, perm: Permission) -> Result<BorrowTag, AliasingError> {
+        self.find(parent)?;
+        let tag = self.next_tag;
+        self.next_tag += 1;
+        self.items.push(BorrowItem { tag, perm });
+        Ok(tag)
+    }
+
+    /// Records a write through `tag`: pops every item above the topmost
+    /// item that grants write permission to `tag`. Returns an
+    /// `AliasingError` if no item for `tag` grants write permission.
+    pub fn write(&mut self, tag: BorrowTag) -> Result<(), AliasingError> {
+        let idx = self.find_write_granting(tag)?;
+        self.items.truncate(idx + 1);
+        Ok(())
+    }
+
+    /// Records a read through `tag`: pops down to the topmost item
+    /// granting read to `tag`, leaving frozen `SharedReadOnly` items
+    /// above it intact.
+    pub fn read(&mut self, tag: BorrowTag) -> Result<(), AliasingError> {
+        let idx = self.find(tag)?;
+        let mut i = self.items.len();
+        while i > idx + 1 {
+            if self.items[i - 1].perm != Permission::SharedReadOnly {
+                self.items.remove(i - 1);
+            }
+            i -= 1;
+        }
+        Ok(())
+    }
+
+    fn find(&self, tag: BorrowTag) -> Result<usize, AliasingError> {
+        self.items
+            .iter()
+            .rposition(|item| item.tag == tag)
+            .ok_or(AliasingError { tag })
+    }
+
+    fn find_write_granting(&self, tag: BorrowTag) -> Result<usize, AliasingError> {
+        self.items
+            .iter()
+            .rposition(|item| item.tag == tag && item.perm != Permission::SharedReadOnly)
+            .ok_or(AliasingError { tag })
+    }
+}
+
+impl Default for BorrowStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,61 @@
+//! Vector clocks for happens-before tracking between accesses.
+//!
+//! Each thread holds a clock with one logical timestamp per thread.
+//! Synchronization events (thread spawn, join, lock release→acquire)
+//! merge the participating clocks by taking the componentwise maximum.
+//! Two accesses are concurrent iff neither clock dominates the other.
+
+/// A vector clock: one logical timestamp per thread, indexed by thread id.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VectorClock(Vec<u64>);
+
+impl VectorClock {
+    /// Returns a clock with all components at zero.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    fn ensure_len(&mut self, len: usize) {
+        if self.0.len() < len {
+            self.0.resize(len, 0);
+        }
+    }
+
+    /// Returns the logical timestamp for `thread` (zero if never observed).
+    pub fn get(&self, thread: usize) -> u64 {
+        self.0.get(thread).copied().unwrap_or(0)
+    }
+
+    /// Increments this clock's own component for `thread`. Called on
+    /// every operation the owning thread performs.
+    pub fn increment(&mut self, thread: usize) {
+        self.ensure_len(thread + 1);
+        self.0[thread] += 1;
+    }
+
+    /// Merges `other` into `self` by taking the componentwise maximum,
+    /// as happens on thread spawn, join, and lock handoff.
+    pub fn merge(&mut self, other: &VectorClock) {
+        self.ensure_len(other.0.len());
+        for (i, &v) in other.0.iter().enumerate() {
+            if v > self.0[i] {
+                self.0[i] = v;
+            }
+        }
+    }
+
+    /// Returns true if every component of `self` is `<=` the
+    /// corresponding component of `other`, i.e. `self` happens-before or
+    /// is equal to `other`.
+    pub fn happens_before_or_eq(&self, other: &VectorClock) -> bool {
+        let len = self.0.len().max(other.0.len());
+        (0..len).all(|i| self.get(i) <= other.get(i))
+    }
+
+    /// Two clocks are concurrent iff neither dominates the other, i.e.
+    /// there exist components where `self > other` and where `other >
+    /// self`. Only concurrent accesses are genuine DPOR dependencies.
+    pub fn concurrent(&self, other: &VectorClock) -> bool {
+        !self.happens_before_or_eq(other) && !other.happens_before_or_eq(self)
+    }
+}
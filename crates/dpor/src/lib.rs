@@ -0,0 +1,16 @@
+//! Dynamic Partial Order Reduction (DPOR) core.
+//!
+//! `ObjectState` tracks per-object accesses across threads and computes
+//! the dependencies DPOR needs to decide where backtrack points belong.
+
+pub mod access;
+pub mod borrow;
+pub mod clock;
+pub mod object;
+pub mod rwlock;
+
+pub use access::{Access, AccessKind, MemOrdering};
+pub use borrow::{AliasingError, BorrowStack, BorrowTag, Permission};
+pub use clock::VectorClock;
+pub use object::{ObjectId, ObjectState, RaceReport};
+pub use rwlock::{LockMode, LockState};
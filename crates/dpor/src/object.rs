@@ -2,7 +2,9 @@
 
 use std::collections::HashMap;
 
-use crate::access::{Access, AccessKind};
+use crate::access::{Access, AccessKind, MemOrdering};
+use crate::borrow::{AliasingError, BorrowStack, BorrowTag, Permission};
+use crate::clock::VectorClock;
 
 /// Opaque integer ID for shared objects.
 pub type ObjectId = u64;
@@ -22,6 +24,13 @@ pub struct ObjectState {
     per_thread_read: HashMap<usize, Access>,
     /// Per-thread most recent write access.
     per_thread_write: HashMap<usize, Access>,
+    /// Stacked-Borrows aliasing state for this object.
+    borrows: BorrowStack,
+    /// Clock left behind by the most recent release-store (or stronger)
+    /// on this object, for a later acquire-load (or stronger) to merge,
+    /// establishing a happens-before edge. `None` until the first such
+    /// store. Relaxed stores never populate this.
+    release_clock: Option<VectorClock>,
 }
 
 impl ObjectState {
@@ -29,6 +38,53 @@ impl ObjectState {
         Self {
             per_thread_read: HashMap::new(),
             per_thread_write: HashMap::new(),
+            borrows: BorrowStack::new(),
+            release_clock: None,
+        }
+    }
+
+    /// Records a release-store's clock so that a subsequent acquire-load
+    /// on this object synchronizes with it. A `Relaxed` ordering
+    /// contributes no synchronization edge.
+    pub fn record_release(&mut self, ordering: MemOrdering, clock: &VectorClock) {
+        if matches!(
+            ordering,
+            MemOrdering::Release | MemOrdering::AcqRel | MemOrdering::SeqCst
+        ) {
+            self.release_clock = Some(clock.clone());
+        }
+    }
+
+    /// Merges this object's pending release clock into `clock` if
+    /// `ordering` is an acquire (or stronger) load, establishing the
+    /// happens-before edge. A `Relaxed` ordering contributes nothing.
+    pub fn acquire(&self, ordering: MemOrdering, clock: &mut VectorClock) {
+        if matches!(
+            ordering,
+            MemOrdering::Acquire | MemOrdering::AcqRel | MemOrdering::SeqCst
+        ) {
+            if let Some(released) = &self.release_clock {
+                clock.merge(released);
+            }
+        }
+    }
+
+    /// Derives a fresh tag from `parent` and pushes it onto this object's
+    /// borrow stack. A mutable retag should pass `Permission::Unique`; a
+    /// shared retag passes `Permission::SharedReadOnly` or
+    /// `Permission::SharedReadWrite`.
+    pub fn retag(&mut self, parent: BorrowTag, perm: Permission) -> Result<BorrowTag, AliasingError> {
+        self.borrows.retag(parent, perm)
+    }
+
+    /// Checks (and updates) the borrow stack for an access through `tag`.
+    /// A `Write` or `Retag` pops every item above the topmost item
+    /// granting write permission to `tag`; a `Read` pops down to the
+    /// topmost item granting read, leaving frozen items intact.
+    pub fn check_borrow(&mut self, tag: BorrowTag, kind: AccessKind) -> Result<(), AliasingError> {
+        match kind {
+            AccessKind::Read => self.borrows.read(tag),
+            AccessKind::Write | AccessKind::Retag => self.borrows.write(tag),
         }
     }
 
@@ -39,16 +95,31 @@ impl ObjectState {
     ///   Returning both ensures DPOR creates backtrack points at read
     ///   positions (for TOCTOU detection) and write positions (for
     ///   write-write ordering).
-    pub fn dependent_accesses(&self, kind: AccessKind, current_thread: usize) -> Vec<&Access> {
+    /// - A **Retag** is treated exactly like a **Write**: a concurrent
+    ///   access can invalidate a reference before it is used, so DPOR
+    ///   must create the same backtrack points it would for a write.
+    ///
+    /// Only accesses that are genuinely concurrent with `current_clock`
+    /// (i.e. not already ordered by a happens-before edge) are returned;
+    /// this prunes interleavings that synchronization has already
+    /// ordered, which is essential for DPOR to scale.
+    pub fn dependent_accesses(
+        &self,
+        kind: AccessKind,
+        current_thread: usize,
+        current_clock: &VectorClock,
+    ) -> Vec<&Access> {
+        let concurrent = |access: &&Access| current_clock.concurrent(&access.clock);
         match kind {
             AccessKind::Read => {
                 self.per_thread_write
                     .iter()
                     .filter(|(tid, _)| **tid != current_thread)
                     .map(|(_, access)| access)
+                    .filter(concurrent)
                     .collect()
             }
-            AccessKind::Write => {
+            AccessKind::Write | AccessKind::Retag => {
                 let mut result: Vec<&Access> = Vec::new();
                 // Latest read from each other thread
                 for (tid, access) in &self.per_thread_read {
@@ -71,6 +142,7 @@ impl ObjectState {
                         }
                     }
                 }
+                result.retain(concurrent);
                 result
             }
         }
@@ -82,11 +154,67 @@ impl ObjectState {
             AccessKind::Read => {
                 self.per_thread_read.insert(thread_id, access);
             }
-            AccessKind::Write => {
+            AccessKind::Write | AccessKind::Retag => {
                 self.per_thread_write.insert(thread_id, access);
             }
         }
     }
+
+    /// Returns the first prior access that genuinely races with `access`
+    /// (a fresh `kind`-access by `current_thread`), if any. A race exists
+    /// whenever two concurrent accesses touch the same object, at least
+    /// one of them is a write (a `Retag` counts as a write here, matching
+    /// its treatment in `dependent_accesses`), and at least one side is
+    /// non-atomic — an all-atomic conflicting pair is legal and is
+    /// surfaced only as a DPOR dependency to reorder, not a race.
+    pub fn detect_race(
+        &self,
+        access: &Access,
+        kind: AccessKind,
+        current_thread: usize,
+    ) -> Option<RaceReport> {
+        let mut candidates: Vec<(AccessKind, &Access)> = Vec::new();
+        if !matches!(kind, AccessKind::Read) {
+            for (tid, other) in &self.per_thread_read {
+                if *tid != current_thread {
+                    candidates.push((AccessKind::Read, other));
+                }
+            }
+        }
+        for (tid, other) in &self.per_thread_write {
+            if *tid != current_thread {
+                candidates.push((AccessKind::Write, other));
+            }
+        }
+
+        candidates
+            .into_iter()
+            .find(|(_, other)| {
+                access.clock.concurrent(&other.clock) && (!access.is_atomic() || !other.is_atomic())
+            })
+            .map(|(other_kind, other)| RaceReport {
+                thread_a: current_thread,
+                kind_a: kind,
+                path_id_a: access.path_id,
+                thread_b: other.thread_id,
+                kind_b: other_kind,
+                path_id_b: other.path_id,
+            })
+    }
+}
+
+/// A concrete data-race witness: two genuinely concurrent accesses to the
+/// same object where at least one is a write, reported so a caller can
+/// render a message like "data race between (1) Write on thread 2 and
+/// (2) Write on thread 5".
+#[derive(Clone, Copy, Debug)]
+pub struct RaceReport {
+    pub thread_a: usize,
+    pub kind_a: AccessKind,
+    pub path_id_a: u64,
+    pub thread_b: usize,
+    pub kind_b: AccessKind,
+    pub path_id_b: u64,
 }
 
 impl Default for ObjectState {
@@ -0,0 +1,88 @@
+//! Reader-writer lock modeling for DPOR.
+//!
+//! Complements [`ObjectState`](crate::object::ObjectState)'s access
+//! tracking with actual lock-acquisition semantics: any number of
+//! `Shared` holders may proceed concurrently and are mutually
+//! independent, an `Exclusive` acquisition conflicts with every other
+//! holder, and releasing a lock establishes a happens-before edge to
+//! whichever thread acquires it next.
+
+use std::collections::HashMap;
+
+use crate::clock::VectorClock;
+
+/// The mode in which a lock is acquired.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockMode {
+    /// Any number of `Shared` holders may hold the lock concurrently.
+    Shared,
+    /// An `Exclusive` holder conflicts with every other holder.
+    Exclusive,
+}
+
+/// A thread's current hold of a lock.
+#[derive(Clone, Debug)]
+struct Holder {
+    mode: LockMode,
+}
+
+/// Tracks acquisitions of a single `RwLock`-like object for DPOR.
+#[derive(Clone, Debug)]
+pub struct LockState {
+    holders: HashMap<usize, Holder>,
+    /// Merge of the clocks of every release so far, merged into each
+    /// later acquirer to establish a happens-before edge (same mechanism
+    /// for a read-lock release feeding a subsequent writer).
+    released: Option<VectorClock>,
+}
+
+impl LockState {
+    pub fn new() -> Self {
+        Self {
+            holders: HashMap::new(),
+            released: None,
+        }
+    }
+
+    /// Returns the other threads whose holds `mode` by `current_thread`
+    /// depends on: a `Shared` acquisition depends only on `Exclusive`
+    /// holders, while an `Exclusive` acquisition depends on every other
+    /// holder (reader or writer). Reader-vs-reader pairs never appear
+    /// here, so DPOR skips enumerating orderings between them.
+    pub fn dependent_holders(&self, mode: LockMode, current_thread: usize) -> Vec<usize> {
+        self.holders
+            .iter()
+            .filter(|(tid, _)| **tid != current_thread)
+            .filter(|(_, holder)| mode == LockMode::Exclusive || holder.mode == LockMode::Exclusive)
+            .map(|(tid, _)| *tid)
+            .collect()
+    }
+
+    /// Acquires the lock for `thread` in `mode`. If a prior release left
+    /// a happens-before clock behind, it is merged into `clock` first so
+    /// the acquisition happens-after that release.
+    pub fn acquire(&mut self, thread: usize, mode: LockMode, clock: &mut VectorClock) {
+        if let Some(released) = &self.released {
+            clock.merge(released);
+        }
+        self.holders.insert(thread, Holder { mode });
+    }
+
+    /// Releases `thread`'s hold, merging its clock into any clock left by
+    /// previous releases so the next acquirer (shared or exclusive)
+    /// happens-after *every* prior release, not just the most recent one
+    /// — e.g. when two `Shared` holders release independently before an
+    /// `Exclusive` acquirer comes along.
+    pub fn release(&mut self, thread: usize, clock: &VectorClock) {
+        self.holders.remove(&thread);
+        self.released
+            .get_or_insert_with(VectorClock::new)
+            .merge(clock);
+    }
+}
+
+impl Default for LockState {
+    fn default() -> Self {
+        Self::new()
+    }
+}